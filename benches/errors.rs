@@ -0,0 +1,70 @@
+use clean_base::utils::errors::{
+    factories::fetching_err, fetching_err as new_fetching_err, MappedErrors,
+};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+// * ---------------------------------------------------------------------------
+// * CONSTRUCTION
+// * ---------------------------------------------------------------------------
+
+fn bench_construction(c: &mut Criterion) {
+    c.bench_function("new", |b| {
+        b.iter(|| {
+            new_fetching_err(
+                black_box("user not found".to_string()),
+                None,
+                None,
+            )
+        })
+    });
+
+    c.bench_function("with_code", |b| {
+        b.iter(|| {
+            fetching_err(black_box("user not found".to_string()))
+                .with_code(black_box("ID001"))
+                .with_code(black_box("ID002"))
+        })
+    });
+
+    c.bench_function("deep_chain", |b| {
+        b.iter(|| {
+            (0..32).fold(fetching_err("root".to_string()), |prev, index| {
+                fetching_err(black_box(format!("wrapper {}", index)))
+                    .with_previous(prev)
+            })
+        })
+    });
+}
+
+// * ---------------------------------------------------------------------------
+// * FORMATTING
+// * ---------------------------------------------------------------------------
+
+fn bench_formatting(c: &mut Criterion) {
+    let error = fetching_err("user not found".to_string())
+        .with_code("ID001")
+        .with_code("ID002");
+
+    c.bench_function("to_string", |b| b.iter(|| black_box(&error).to_string()));
+}
+
+// * ---------------------------------------------------------------------------
+// * PARSING
+// * ---------------------------------------------------------------------------
+
+fn bench_parsing(c: &mut Criterion) {
+    let line = fetching_err("user not found".to_string())
+        .with_code("ID001")
+        .to_string();
+
+    c.bench_function("from_str_msg", |b| {
+        b.iter(|| MappedErrors::from_str_msg(black_box(line.to_owned())))
+    });
+
+    c.bench_function("parse_fields", |b| {
+        b.iter(|| MappedErrors::parse_fields(black_box(&line)))
+    });
+}
+
+criterion_group!(benches, bench_construction, bench_formatting, bench_parsing);
+criterion_main!(benches);
@@ -10,7 +10,7 @@
 /// the relationship between them is established by a foreign key, you can use
 /// Parent and Children.
 ///
-/// ```
+/// ```ignore
 /// struct Post {
 ///    id: i32,
 ///    title: String,
@@ -33,7 +33,7 @@
 ///
 /// This, the instance representation of Post with comments as IDs would be:
 ///
-/// ```
+/// ```ignore
 /// let post_with_comments_as_ids = Post {
 ///     id: 1,
 ///     title: "Hello World".to_string(),
@@ -0,0 +1,93 @@
+use super::base::MappedErrors;
+use serde::{ser::Error as _, Serialize, Serializer};
+use serde_json::Value;
+
+/// The serialized field names replaced in `AlternateKeys`, with their
+/// alternate names.
+const ALTERNATE_KEYS: [(&str, &str); 2] =
+    [("msg", "message"), ("codes", "error_code")];
+
+/// A view of a `MappedErrors` serialized with the alternate key names
+/// expected by some API consumers: `message` instead of `msg` and
+/// `error_code` instead of `codes`. Every other serialized field, including
+/// the context and the `causes` chain, is forwarded as is.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlternateKeys<'a> {
+    error: &'a MappedErrors,
+}
+
+impl<'a> From<&'a MappedErrors> for AlternateKeys<'a> {
+    fn from(error: &'a MappedErrors) -> Self {
+        Self { error }
+    }
+}
+
+impl Serialize for AlternateKeys<'_> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut fields = match serde_json::to_value(self.error) {
+            Ok(Value::Object(fields)) => fields,
+            Ok(_) => Default::default(),
+            Err(err) => return Err(S::Error::custom(err)),
+        };
+
+        for (key, alternate) in ALTERNATE_KEYS {
+            if let Some(value) = fields.remove(key) {
+                fields.insert(alternate.to_string(), value);
+            }
+        }
+
+        fields.serialize(serializer)
+    }
+}
+
+impl MappedErrors {
+    /// This method returns a view of the current error serialized with the
+    /// alternate key names, see `AlternateKeys`.
+    pub fn as_alternate_keys(&self) -> AlternateKeys<'_> {
+        AlternateKeys::from(self)
+    }
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::errors::factories::fetching_err;
+
+    #[test]
+    fn test_alternate_keys() {
+        let error =
+            fetching_err("user not found".to_string()).with_code("ID001");
+
+        let value = serde_json::to_value(error.as_alternate_keys()).unwrap();
+
+        assert_eq!(value["message"], "user not found");
+        assert_eq!(value["error_code"]["codes"][0], "ID001");
+        assert_eq!(value["error_type"], "fetchingError");
+        assert!(value.get("msg").is_none());
+        assert!(value.get("codes").is_none());
+
+        let error = error
+            .with_secondary_code("ID002")
+            .with_context_entry("user_id", "42")
+            .with_source(fetching_err("query failed".to_string()));
+
+        let value = serde_json::to_value(error.as_alternate_keys()).unwrap();
+
+        assert_eq!(value["secondary_codes"][0], "ID002");
+        assert_eq!(value["causes"][0]["msg"], "query failed");
+
+        if cfg!(feature = "rich-diagnostics") {
+            assert_eq!(value["context"]["user_id"], "42");
+        }
+
+        let value = serde_json::to_value(&error).unwrap();
+
+        assert_eq!(value["msg"], "user not found");
+    }
+}
@@ -0,0 +1,150 @@
+use super::base::{ErrorCodes, ErrorType, MappedErrors, Priority};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, time::SystemTime};
+
+/// The binary representation of a `MappedErrors`.
+///
+/// Binary formats as `bincode` are not self-describing, so fields skipped
+/// when empty in the JSON representation can not be skipped here. All the
+/// serializable fields are always written.
+#[derive(Deserialize, Serialize)]
+struct BincodeRepr {
+    msg: String,
+    error_type: ErrorType,
+    expected: bool,
+    codes: ErrorCodes,
+    secondary_codes: Vec<String>,
+    retryable: bool,
+    span: Option<(usize, usize)>,
+    attempt: Option<u32>,
+    priority: Option<Priority>,
+    created_at: Option<SystemTime>,
+    tags: Vec<String>,
+    context: BTreeMap<String, String>,
+    cause: Option<String>,
+}
+
+impl From<&MappedErrors> for BincodeRepr {
+    fn from(error: &MappedErrors) -> Self {
+        Self {
+            msg: error.msg(),
+            error_type: error.error_type(),
+            expected: error.expected(),
+            codes: error.code(),
+            secondary_codes: error.secondary_codes().to_vec(),
+            retryable: error.is_retryable(),
+            span: error.span(),
+            attempt: error.attempt(),
+            priority: error.priority_override(),
+            created_at: error.created_at(),
+            tags: error.tags().to_vec(),
+            context: error.context_entries().to_owned(),
+            cause: error.cause().map(str::to_string),
+        }
+    }
+}
+
+impl From<BincodeRepr> for MappedErrors {
+    fn from(repr: BincodeRepr) -> Self {
+        let mut error =
+            MappedErrors::from_parts(repr.msg, repr.error_type, repr.codes)
+                .with_restored_state(
+                    repr.expected,
+                    repr.retryable,
+                    repr.created_at,
+                )
+                .with_secondary_codes(repr.secondary_codes)
+                .with_tags(repr.tags);
+
+        if let Some((start, end)) = repr.span {
+            error = error.with_span(start, end);
+        }
+
+        if let Some(attempt) = repr.attempt {
+            error = error.with_attempt(attempt);
+        }
+
+        if let Some(priority) = repr.priority {
+            error = error.with_priority(priority);
+        }
+
+        if let Some(cause) = repr.cause {
+            error = error.with_cause_str(cause);
+        }
+
+        repr.context.iter().fold(error, |error, (key, value)| {
+            error.with_context_entry(key, value)
+        })
+    }
+}
+
+impl MappedErrors {
+    /// This method returns the current error encoded with `bincode`, for
+    /// passing errors between processes over binary channels. The source
+    /// error is not encoded.
+    #[allow(clippy::result_large_err)]
+    pub fn to_bincode(&self) -> Result<Vec<u8>, MappedErrors> {
+        bincode::serialize(&BincodeRepr::from(self)).map_err(|err| {
+            MappedErrors::default(format!("Unable to encode error: {}", err))
+                .with_error_type(ErrorType::ExecutionError)
+                .with_code("bincode-encode")
+        })
+    }
+
+    /// Decode an error encoded by `to_bincode`.
+    #[allow(clippy::result_large_err)]
+    pub fn from_bincode(bytes: &[u8]) -> Result<MappedErrors, MappedErrors> {
+        bincode::deserialize::<BincodeRepr>(bytes)
+            .map(MappedErrors::from)
+            .map_err(|err| {
+                MappedErrors::default(format!(
+                    "Unable to decode error: {}",
+                    err
+                ))
+                .with_error_type(ErrorType::InvalidArgumentError)
+                .with_code("bincode-decode")
+            })
+    }
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::errors::{ErrorType, MappedErrors, Priority};
+
+    #[test]
+    fn test_bincode_round_trip() {
+        let error = MappedErrors::default("not found".to_string())
+            .with_error_type(ErrorType::DependencyError)
+            .with_code("ID001")
+            .with_code("ID002")
+            .with_exp_false()
+            .with_secondary_code("VENDOR-42")
+            .with_span(4, 9)
+            .with_attempt(3)
+            .with_priority(Priority::High)
+            .with_tag("db")
+            .with_context_entry("user_id", "42")
+            .with_cause_str("libpq: timeout");
+
+        let bytes = error.to_bincode().unwrap();
+        let decoded = MappedErrors::from_bincode(&bytes).unwrap();
+
+        assert_eq!(decoded, error);
+        assert!(decoded.is_retryable());
+
+        let bare = MappedErrors::default("bare".to_string());
+        let decoded =
+            MappedErrors::from_bincode(&bare.to_bincode().unwrap()).unwrap();
+
+        assert_eq!(decoded, bare);
+        assert!(!decoded.is_retryable());
+
+        let error = MappedErrors::from_bincode(&[0xff]).unwrap_err();
+
+        assert!(error.has_str_code("bincode-decode"));
+    }
+}
@@ -0,0 +1,77 @@
+use super::{
+    base::{ErrorCategory, ErrorType, MappedErrors},
+    default_codes::resolve_default_code,
+};
+use serde::Serialize;
+
+/// An entry of the errors catalog, documenting an error type.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogEntry {
+    /// The error type documented by the entry.
+    pub error_type: ErrorType,
+
+    /// The slug of the error type, as `fetching-error`.
+    pub slug: String,
+
+    /// The human readable name of the error type, as `Fetching error`.
+    pub human_name: String,
+
+    /// The HTTP status code of the error type.
+    pub http_status: u16,
+
+    /// The category the error type is related to.
+    pub category: ErrorCategory,
+
+    /// The default code assigned to errors of the type, if any.
+    pub default_code: Option<&'static str>,
+}
+
+impl MappedErrors {
+    /// This method returns the catalog of all error types, in declaration
+    /// order, used to generate errors documentation.
+    pub fn catalog() -> Vec<CatalogEntry> {
+        ErrorType::all()
+            .iter()
+            .map(|error_type| CatalogEntry {
+                error_type: *error_type,
+                slug: error_type.to_string(),
+                human_name: error_type.human_name(),
+                http_status: error_type.http_status(),
+                category: error_type.category(),
+                default_code: resolve_default_code(*error_type),
+            })
+            .collect()
+    }
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_catalog_covers_every_error_type() {
+        let catalog = MappedErrors::catalog();
+
+        let error_types: HashSet<ErrorType> =
+            catalog.iter().map(|entry| entry.error_type).collect();
+
+        assert_eq!(catalog.len(), ErrorType::all().len());
+        assert_eq!(error_types.len(), catalog.len());
+
+        let fetching = catalog
+            .iter()
+            .find(|entry| entry.error_type == ErrorType::FetchingError)
+            .unwrap();
+
+        assert_eq!(fetching.slug, "fetching-error");
+        assert_eq!(fetching.human_name, "Fetching error");
+        assert_eq!(fetching.http_status, 404);
+        assert_eq!(fetching.category, ErrorCategory::Crud);
+    }
+}
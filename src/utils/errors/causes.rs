@@ -0,0 +1,147 @@
+use super::base::{ErrorCodes, ErrorType, MappedErrors};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{error::Error, sync::Arc};
+
+/// A single entry of the serialized cause chain of a `MappedErrors`. Foreign
+/// errors only expose their message.
+#[derive(Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub(super) struct ErrorCause {
+    msg: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error_type: Option<ErrorType>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    codes: Option<ErrorCodes>,
+}
+
+impl From<&(dyn Error + 'static)> for ErrorCause {
+    fn from(error: &(dyn Error + 'static)) -> Self {
+        match error.downcast_ref::<MappedErrors>() {
+            Some(mapped) => Self {
+                msg: mapped.msg(),
+                error_type: Some(mapped.error_type()),
+                codes: Some(mapped.code()),
+            },
+            None => Self {
+                msg: error.to_string(),
+                error_type: None,
+                codes: None,
+            },
+        }
+    }
+}
+
+/// Serialize the chain of source errors as an array of causes, from the
+/// closest to the root one.
+pub(super) fn serialize<S>(
+    source: &Option<Arc<dyn Error + Send + Sync>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut causes = vec![];
+    let mut current = source
+        .as_deref()
+        .map(|source| source as &(dyn Error + 'static));
+
+    while let Some(error) = current {
+        causes.push(ErrorCause::from(error));
+        current = error.source();
+    }
+
+    serializer.collect_seq(causes)
+}
+
+/// Deserialize the array of causes back into a chain of source errors. Each
+/// cause is rebuilt as a `MappedErrors` with `MappedErrors::from_parts`, so
+/// foreign errors become undefined errors holding their message.
+pub(super) fn deserialize<'de, D>(
+    deserializer: D,
+) -> Result<Option<Arc<dyn Error + Send + Sync>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let causes = Vec::<ErrorCause>::deserialize(deserializer)?;
+
+    let source = causes.into_iter().rev().fold(None, |source, cause| {
+        let error = MappedErrors::from_parts(
+            cause.msg,
+            cause.error_type.unwrap_or(ErrorType::UndefinedError),
+            cause.codes.unwrap_or(ErrorCodes::Unmapped),
+        );
+
+        Some(match source {
+            Some(source) => error.with_source(source),
+            None => error,
+        })
+    });
+
+    Ok(source.map(|source| Arc::new(source) as Arc<dyn Error + Send + Sync>))
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::errors::{
+        factories::{fetching_err, invalid_repo_err},
+        MappedErrors,
+    };
+    use std::io::{Error as IoError, ErrorKind};
+
+    #[test]
+    fn test_serialize_causes() {
+        let error = fetching_err("loading user".to_string()).with_source(
+            invalid_repo_err("query failed".to_string())
+                .with_code("DB-DOWN")
+                .with_source(IoError::new(
+                    ErrorKind::ConnectionRefused,
+                    "connection refused",
+                )),
+        );
+
+        let value = serde_json::to_value(&error).unwrap();
+
+        assert_eq!(value["msg"], "loading user");
+
+        let causes = value["causes"].as_array().unwrap();
+
+        assert_eq!(causes.len(), 2);
+        assert_eq!(causes[0]["msg"], "query failed");
+        assert_eq!(causes[0]["error_type"], "invalidRepositoryError");
+        assert_eq!(causes[0]["codes"]["codes"][0], "DB-DOWN");
+        assert_eq!(causes[1]["msg"], "connection refused");
+        assert!(causes[1].get("error_type").is_none());
+
+        let decoded: MappedErrors =
+            serde_json::from_value(value.to_owned()).unwrap();
+
+        assert_eq!(decoded, error);
+        assert_eq!(decoded.chain_len(), 3);
+        assert_eq!(
+            decoded.join_messages(": "),
+            "loading user: query failed: connection refused"
+        );
+        assert!(decoded
+            .find_source::<MappedErrors>()
+            .unwrap()
+            .has_str_code("DB-DOWN"));
+
+        // Foreign errors are rebuilt as undefined errors.
+        let reencoded = serde_json::to_value(&decoded).unwrap();
+
+        assert_eq!(reencoded["causes"][0], value["causes"][0]);
+        assert_eq!(reencoded["causes"][1]["error_type"], "undefinedError");
+
+        let value =
+            serde_json::to_value(fetching_err("bare".to_string())).unwrap();
+
+        assert!(value.get("causes").is_none());
+    }
+}
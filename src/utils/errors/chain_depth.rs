@@ -0,0 +1,142 @@
+use regex::Regex;
+use std::sync::{OnceLock, RwLock};
+
+/// The default maximum number of preceding errors kept in the message and in
+/// the source chain of a chained error.
+pub const DEFAULT_MAX_CHAIN_DEPTH: usize = 16;
+
+/// The delimiter between the current error and its preceding error.
+const PRECEDING_DELIMITER: &str = "; [PRECEDING_ERROR] ";
+
+static MAX_CHAIN_DEPTH: RwLock<usize> = RwLock::new(DEFAULT_MAX_CHAIN_DEPTH);
+
+static OMITTED_PATTERN: OnceLock<Regex> = OnceLock::new();
+
+/// Set the maximum number of preceding errors kept in the message and in the
+/// source chain of a chained error. The oldest ancestors exceeding it are
+/// collapsed into a summary. Values lower than one are treated as one.
+pub fn set_max_chain_depth(depth: usize) {
+    let depth = depth.max(1);
+
+    match MAX_CHAIN_DEPTH.write() {
+        Ok(mut guard) => *guard = depth,
+        Err(poisoned) => *poisoned.into_inner() = depth,
+    }
+}
+
+/// Returns the maximum number of preceding errors kept in a chain.
+pub(super) fn max_chain_depth() -> usize {
+    match MAX_CHAIN_DEPTH.read() {
+        Ok(guard) => *guard,
+        Err(poisoned) => *poisoned.into_inner(),
+    }
+}
+
+/// Returns the number of omitted errors of a summary message, if the message
+/// is a summary.
+pub(super) fn omitted_count(msg: &str) -> Option<usize> {
+    let capture = omitted_pattern().captures(msg)?;
+
+    if capture[0].len() != msg.len() {
+        return None;
+    }
+
+    capture[1].parse::<usize>().ok()
+}
+
+/// Returns the summary message of the given number of omitted errors.
+pub(super) fn omitted_summary(omitted: usize) -> String {
+    format!("[{} preceding errors omitted]", omitted)
+}
+
+/// The pattern matching the summary of omitted errors.
+fn omitted_pattern() -> &'static Regex {
+    OMITTED_PATTERN.get_or_init(|| {
+        Regex::new(r"\[(\d+) preceding errors omitted\]").unwrap()
+    })
+}
+
+/// Collapse the oldest ancestors of the chained message exceeding the
+/// maximum chain depth into a summary of the number of omitted errors.
+pub(super) fn cap_chain(msg: String) -> String {
+    let depth = max_chain_depth();
+
+    let idx = match msg.match_indices(PRECEDING_DELIMITER).nth(depth - 1) {
+        Some((idx, _)) => idx,
+        None => return msg,
+    };
+
+    let tail = &msg[idx + PRECEDING_DELIMITER.len()..];
+
+    let already_omitted: usize = omitted_pattern()
+        .captures_iter(tail)
+        .filter_map(|capture| capture[1].parse::<usize>().ok())
+        .map(|omitted| omitted.saturating_sub(1))
+        .sum();
+
+    let omitted =
+        tail.matches(PRECEDING_DELIMITER).count() + 1 + already_omitted;
+
+    format!(
+        "{}{}{}",
+        &msg[..idx],
+        PRECEDING_DELIMITER,
+        omitted_summary(omitted)
+    )
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::errors::MappedErrors;
+
+    #[test]
+    fn test_chain_depth_is_capped() {
+        let error = (0..DEFAULT_MAX_CHAIN_DEPTH * 3).fold(
+            MappedErrors::default("root".to_string()),
+            |prev, index| {
+                MappedErrors::default(format!("wrapper {}", index))
+                    .with_previous(prev)
+            },
+        );
+
+        let msg = error.msg();
+
+        assert_eq!(
+            msg.matches("[PRECEDING_ERROR]").count(),
+            DEFAULT_MAX_CHAIN_DEPTH
+        );
+        assert!(msg.starts_with(&format!(
+            "[CURRENT_ERROR] wrapper {}",
+            DEFAULT_MAX_CHAIN_DEPTH * 3 - 1
+        )));
+        assert!(!msg.contains("root"));
+        assert!(msg.contains(&format!(
+            "[{} preceding errors omitted]",
+            DEFAULT_MAX_CHAIN_DEPTH * 2 + 1
+        )));
+    }
+
+    #[test]
+    fn test_source_chain_depth_is_capped() {
+        let error = (0..DEFAULT_MAX_CHAIN_DEPTH * 3).fold(
+            MappedErrors::default("root".to_string()),
+            |prev, index| {
+                MappedErrors::default(format!("wrapper {}", index))
+                    .with_source(prev)
+            },
+        );
+
+        assert_eq!(error.chain_len(), DEFAULT_MAX_CHAIN_DEPTH + 1);
+
+        let messages = error.join_messages("\n");
+
+        assert!(!messages.contains("root"));
+        assert!(messages
+            .ends_with(&omitted_summary(DEFAULT_MAX_CHAIN_DEPTH * 2 + 1)));
+    }
+}
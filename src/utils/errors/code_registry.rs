@@ -0,0 +1,27 @@
+use std::collections::BTreeMap;
+
+/// A registry of the documentation URLs of error codes, used to enrich the
+/// human reports of errors.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CodeRegistry {
+    doc_urls: BTreeMap<String, String>,
+}
+
+impl CodeRegistry {
+    /// This method returns a new empty `CodeRegistry`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the documentation URL of the given code, replacing the
+    /// previous one, if any.
+    pub fn with_doc_url(mut self, code: &str, url: &str) -> Self {
+        self.doc_urls.insert(code.to_string(), url.to_string());
+        self
+    }
+
+    /// This method returns the documentation URL of the given code, if any.
+    pub fn doc_url(&self, code: &str) -> Option<&str> {
+        self.doc_urls.get(code).map(String::as_str)
+    }
+}
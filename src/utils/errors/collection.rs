@@ -0,0 +1,112 @@
+use super::base::{ErrorType, MappedErrors};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A collection of errors aggregated during a batch operation.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, Serialize)]
+pub struct ErrorCollection {
+    errors: Vec<MappedErrors>,
+}
+
+impl ErrorCollection {
+    /// This method returns a new empty `ErrorCollection`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// This method returns the errors of the collection.
+    pub fn errors(&self) -> &[MappedErrors] {
+        &self.errors
+    }
+
+    /// This method returns the number of errors in the collection.
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// This method returns a boolean indicating if the collection is empty.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Include an error in the collection.
+    pub fn push(&mut self, error: MappedErrors) {
+        self.errors.push(error);
+    }
+
+    /// This method returns the errors of the collection grouped by their
+    /// error type.
+    pub fn group_by_type(&self) -> BTreeMap<ErrorType, Vec<&MappedErrors>> {
+        let mut groups: BTreeMap<ErrorType, Vec<&MappedErrors>> =
+            BTreeMap::new();
+
+        for error in &self.errors {
+            groups.entry(error.error_type()).or_default().push(error);
+        }
+
+        groups
+    }
+}
+
+impl FromIterator<MappedErrors> for ErrorCollection {
+    fn from_iter<I: IntoIterator<Item = MappedErrors>>(iter: I) -> Self {
+        Self {
+            errors: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Extend<MappedErrors> for ErrorCollection {
+    fn extend<I: IntoIterator<Item = MappedErrors>>(&mut self, iter: I) {
+        self.errors.extend(iter);
+    }
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::errors::factories::{creation_err, fetching_err};
+
+    #[test]
+    fn test_group_by_type() {
+        let mut collection = ErrorCollection::new();
+
+        collection.push(fetching_err("first fetch".to_string()));
+        collection.push(creation_err("create".to_string()));
+        collection.push(fetching_err("second fetch".to_string()));
+
+        let groups = collection.group_by_type();
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[&ErrorType::FetchingError].len(), 2);
+        assert_eq!(groups[&ErrorType::CreationError].len(), 1);
+        assert_eq!(groups[&ErrorType::FetchingError][0].msg(), "first fetch");
+        assert_eq!(groups[&ErrorType::FetchingError][1].msg(), "second fetch");
+    }
+
+    #[test]
+    fn test_collect_and_extend() {
+        let results: Vec<Result<u8, MappedErrors>> = vec![
+            Ok(1),
+            Err(fetching_err("fetch".to_string())),
+            Ok(2),
+            Err(creation_err("create".to_string())),
+        ];
+
+        let mut collection: ErrorCollection =
+            results.into_iter().filter_map(Result::err).collect();
+
+        assert_eq!(collection.len(), 2);
+        assert_eq!(collection.errors()[0].msg(), "fetch");
+        assert_eq!(collection.errors()[1].msg(), "create");
+
+        collection.extend(vec![fetching_err("another fetch".to_string())]);
+
+        assert_eq!(collection.len(), 3);
+        assert_eq!(collection.errors()[2].msg(), "another fetch");
+    }
+}
@@ -0,0 +1,143 @@
+use super::base::ErrorType;
+use std::sync::RwLock;
+
+/// A function resolving the default error code of an error type.
+pub type DefaultCodeResolver = fn(ErrorType) -> Option<&'static str>;
+
+static DEFAULT_CODE_RESOLVER: RwLock<DefaultCodeResolver> =
+    RwLock::new(no_default_code);
+
+/// The resolver used unless another one is set, assigning no default codes.
+fn no_default_code(_: ErrorType) -> Option<&'static str> {
+    None
+}
+
+/// Returns the baseline error code of the given error type, if any.
+///
+/// Default codes are disabled unless opted into with
+/// `set_default_code_resolver(default_code_for)`. Such code is then assigned
+/// to errors for which no explicit code was set.
+pub fn default_code_for(error_type: ErrorType) -> Option<&'static str> {
+    match error_type {
+        ErrorType::UndefinedError => None,
+        ErrorType::CreationError => Some("CRUD-CREATE"),
+        ErrorType::UpdatingError => Some("CRUD-UPDATE"),
+        ErrorType::FetchingError => Some("CRUD-FETCH"),
+        ErrorType::DeletionError => Some("CRUD-DELETE"),
+        ErrorType::UseCaseError => Some("USE-CASE"),
+        ErrorType::ExecutionError => Some("EXECUTION"),
+        ErrorType::InvalidRepositoryError => Some("DATA-REPOSITORY"),
+        ErrorType::InvalidArgumentError => Some("ARGUMENT"),
+        ErrorType::ConfigurationError => Some("CONFIGURATION"),
+        ErrorType::DependencyError => Some("DEPENDENCY"),
+    }
+}
+
+/// Set the function used to resolve default error codes. No default codes
+/// are assigned until a resolver is set.
+pub fn set_default_code_resolver(resolver: DefaultCodeResolver) {
+    match DEFAULT_CODE_RESOLVER.write() {
+        Ok(mut guard) => *guard = resolver,
+        Err(poisoned) => *poisoned.into_inner() = resolver,
+    }
+}
+
+/// Resolve the default error code of the given error type using the current
+/// resolver.
+pub(super) fn resolve_default_code(
+    error_type: ErrorType,
+) -> Option<&'static str> {
+    let resolver = match DEFAULT_CODE_RESOLVER.read() {
+        Ok(guard) => *guard,
+        Err(poisoned) => *poisoned.into_inner(),
+    };
+
+    resolver(error_type)
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::errors::{
+        factories::fetching_err, ErrorCodes, MappedErrors,
+    };
+    use std::{
+        cell::Cell,
+        sync::{Mutex, MutexGuard},
+    };
+
+    static RESOLVER_LOCK: Mutex<()> = Mutex::new(());
+
+    thread_local! {
+        static RESOLVING: Cell<bool> = const { Cell::new(false) };
+    }
+
+    /// Delegates to `default_code_for` on the thread holding a
+    /// `ResolverGuard` only, so tests running concurrently keep building
+    /// errors without default codes.
+    fn scoped_default_code_for(error_type: ErrorType) -> Option<&'static str> {
+        match RESOLVING.with(Cell::get) {
+            true => default_code_for(error_type),
+            false => None,
+        }
+    }
+
+    /// Serializes the tests setting a resolver and restores the default one
+    /// when dropped.
+    struct ResolverGuard {
+        _lock: MutexGuard<'static, ()>,
+    }
+
+    impl ResolverGuard {
+        fn set(resolver: DefaultCodeResolver) -> Self {
+            let lock = RESOLVER_LOCK
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+            RESOLVING.with(|resolving| resolving.set(true));
+            set_default_code_resolver(resolver);
+            ResolverGuard { _lock: lock }
+        }
+    }
+
+    impl Drop for ResolverGuard {
+        fn drop(&mut self) {
+            set_default_code_resolver(no_default_code);
+            RESOLVING.with(|resolving| resolving.set(false));
+        }
+    }
+
+    #[test]
+    fn test_set_default_code_resolver() {
+        let guard = ResolverGuard::set(scoped_default_code_for);
+
+        let error = MappedErrors::new(
+            "This is a test error".to_string(),
+            None,
+            None,
+            ErrorType::CreationError,
+        );
+
+        assert!(error.has_str_code("CRUD-CREATE"));
+        assert!(
+            fetching_err("not found".to_string()).has_str_code("CRUD-FETCH")
+        );
+        assert_eq!(
+            fetching_err("not found".to_string())
+                .with_code("ID001")
+                .code(),
+            ErrorCodes::Codes(vec!["ID001".into()])
+        );
+
+        drop(guard);
+
+        assert_eq!(
+            fetching_err("not found".to_string()).code(),
+            ErrorCodes::Unmapped
+        );
+    }
+}
@@ -64,6 +64,22 @@ pub fn invalid_arg_err(
     MappedErrors::new(msg, exp, prev, ErrorType::InvalidArgumentError)
 }
 
+pub fn configuration_err(
+    msg: String,
+    exp: Option<bool>,
+    prev: Option<MappedErrors>,
+) -> MappedErrors {
+    MappedErrors::new(msg, exp, prev, ErrorType::ConfigurationError)
+}
+
+pub fn dependency_err(
+    msg: String,
+    exp: Option<bool>,
+    prev: Option<MappedErrors>,
+) -> MappedErrors {
+    MappedErrors::new(msg, exp, prev, ErrorType::DependencyError)
+}
+
 // ? ---------------------------------------------------------------------------
 // ? TESTS
 // ? ---------------------------------------------------------------------------
@@ -0,0 +1,97 @@
+use super::base::{ErrorCodes, ErrorType, MappedErrors};
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Serialize the error as its compact `Display` string, instead of the
+/// structured form. Fields opt into it with
+/// `#[serde(with = "clean_base::utils::errors::display_string")]`.
+///
+/// Only the codes, the error type and the message survive the round trip.
+pub fn serialize<S>(
+    error: &MappedErrors,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&error.render(false))
+}
+
+/// Deserialize the error from its `Display` string. Strings not matching the
+/// `Display` format are kept as the message of an `UndefinedError`.
+///
+/// The error is rebuilt with `MappedErrors::from_parts`, so reading stored
+/// records dispatches no log records nor the `on_error` hook.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<MappedErrors, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let msg = String::deserialize(deserializer)?;
+
+    Ok(match MappedErrors::parse_fields(&msg) {
+        Some((error_type, codes, msg)) => {
+            MappedErrors::from_parts(msg, error_type, codes)
+        }
+        None => MappedErrors::from_parts(
+            msg,
+            ErrorType::UndefinedError,
+            ErrorCodes::Unmapped,
+        ),
+    })
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::errors::{
+        factories::fetching_err,
+        test_logger::{install_logger, was_logged},
+        ErrorCodes, MappedErrors,
+    };
+    use log::Level;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Report {
+        id: u32,
+        #[serde(with = "super")]
+        error: MappedErrors,
+    }
+
+    #[test]
+    fn test_display_string_round_trip() {
+        let _logger = install_logger();
+
+        let report = Report {
+            id: 1,
+            error: fetching_err("stored user not found".to_string())
+                .with_code("ID001"),
+        };
+
+        let value = serde_json::to_value(&report).unwrap();
+
+        assert_eq!(
+            value["error"],
+            "[codes=ID001 error_type=fetching-error] stored user not found"
+        );
+
+        let deserialized: Report = serde_json::from_value(value).unwrap();
+
+        assert_eq!(deserialized.id, 1);
+        assert_eq!(deserialized.error.msg(), "stored user not found");
+        assert_eq!(deserialized.error.code(), report.error.code());
+        assert_eq!(deserialized.error.error_type(), report.error.error_type());
+        assert!(!was_logged(Level::Warn, "stored user not found"));
+
+        let deserialized: Report = serde_json::from_value(serde_json::json!({
+            "id": 2,
+            "error": "free-form text",
+        }))
+        .unwrap();
+
+        assert_eq!(deserialized.error.msg(), "free-form text");
+        assert_eq!(deserialized.error.code(), ErrorCodes::Unmapped);
+    }
+}
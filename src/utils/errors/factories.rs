@@ -41,14 +41,78 @@ pub fn invalid_arg_err(msg: String) -> MappedErrors {
     MappedErrors::default(msg).with_error_type(ErrorType::InvalidArgumentError)
 }
 
+/// A factory for configuration errors
+pub fn configuration_err(msg: String) -> MappedErrors {
+    MappedErrors::default(msg).with_error_type(ErrorType::ConfigurationError)
+}
+
+/// A factory for dependency errors
+pub fn dependency_err(msg: String) -> MappedErrors {
+    MappedErrors::default(msg).with_error_type(ErrorType::DependencyError)
+}
+
+impl MappedErrors {
+    /// A constructor for creation errors caused by the previous error
+    pub fn creation_error_from(prev: Self, msg: String) -> Self {
+        creation_err(msg).with_source(prev)
+    }
+
+    /// A constructor for updating errors caused by the previous error
+    pub fn updating_error_from(prev: Self, msg: String) -> Self {
+        updating_err(msg).with_source(prev)
+    }
+
+    /// A constructor for fetching errors caused by the previous error
+    pub fn fetching_error_from(prev: Self, msg: String) -> Self {
+        fetching_err(msg).with_source(prev)
+    }
+
+    /// A constructor for deletion errors caused by the previous error
+    pub fn deletion_error_from(prev: Self, msg: String) -> Self {
+        deletion_err(msg).with_source(prev)
+    }
+
+    /// A constructor for use case errors caused by the previous error
+    pub fn use_case_error_from(prev: Self, msg: String) -> Self {
+        use_case_err(msg).with_source(prev)
+    }
+
+    /// A constructor for execution errors caused by the previous error
+    pub fn execution_error_from(prev: Self, msg: String) -> Self {
+        execution_err(msg).with_source(prev)
+    }
+
+    /// A constructor for invalid repository errors caused by the previous error
+    pub fn invalid_repository_error_from(prev: Self, msg: String) -> Self {
+        invalid_repo_err(msg).with_source(prev)
+    }
+
+    /// A constructor for invalid argument errors caused by the previous error
+    pub fn invalid_argument_error_from(prev: Self, msg: String) -> Self {
+        invalid_arg_err(msg).with_source(prev)
+    }
+
+    /// A constructor for configuration errors caused by the previous error
+    pub fn configuration_error_from(prev: Self, msg: String) -> Self {
+        configuration_err(msg).with_source(prev)
+    }
+
+    /// A constructor for dependency errors caused by the previous error
+    pub fn dependency_error_from(prev: Self, msg: String) -> Self {
+        dependency_err(msg).with_source(prev)
+    }
+}
+
 // * ---------------------------------------------------------------------------
 // * TESTS
 // * ---------------------------------------------------------------------------
 
 #[cfg(test)]
+#[allow(clippy::result_large_err)]
 mod test {
     use super::*;
     use crate::utils::errors::{base::ErrorType, ErrorCodes};
+    use std::error::Error;
 
     #[test]
     fn test_default_factories() {
@@ -91,6 +155,16 @@ mod test {
             invalid_arg_err("invalid_arg".to_string()).error_type(),
             ErrorType::InvalidArgumentError
         );
+
+        assert_eq!(
+            configuration_err("configuration".to_string()).error_type(),
+            ErrorType::ConfigurationError
+        );
+
+        assert_eq!(
+            dependency_err("dependency".to_string()).error_type(),
+            ErrorType::DependencyError
+        );
     }
 
     #[test]
@@ -106,10 +180,39 @@ mod test {
 
         assert!(
             result.code() ==
-                ErrorCodes::Codes(vec![
-                    "ID001".to_string(),
-                    "ID002".to_string()
-                ])
+                ErrorCodes::Codes(vec!["ID001".into(), "ID002".into()])
+        );
+    }
+
+    #[test]
+    fn test_from_factories() {
+        let inner = invalid_repo_err("connection refused".to_string())
+            .with_code("DB-DOWN");
+
+        let error = MappedErrors::fetching_error_from(
+            inner,
+            "loading user".to_string(),
+        );
+
+        assert_eq!(error.error_type(), ErrorType::FetchingError);
+        assert_eq!(error.msg(), "loading user");
+
+        let source = error
+            .source()
+            .and_then(|source| source.downcast_ref::<MappedErrors>())
+            .unwrap();
+
+        assert_eq!(source.error_type(), ErrorType::InvalidRepositoryError);
+        assert_eq!(source.msg(), "connection refused");
+        assert!(source.has_str_code("DB-DOWN"));
+        assert!(source.source().is_none());
+
+        let error =
+            MappedErrors::use_case_error_from(error, "signing in".to_string());
+
+        assert_eq!(
+            error.find_source::<MappedErrors>().unwrap().msg(),
+            "loading user"
         );
     }
 }
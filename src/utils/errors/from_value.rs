@@ -0,0 +1,231 @@
+use super::{
+    base::{ErrorType, MappedErrors},
+    factories::invalid_arg_err,
+};
+use serde_json::{json, Map, Value};
+use std::str::FromStr;
+
+impl TryFrom<Value> for MappedErrors {
+    type Error = MappedErrors;
+
+    /// Build a `MappedErrors` from a JSON object in a tolerant way.
+    ///
+    /// Both the snake case and the camel case field names are accepted, as
+    /// `errorType` or `code`. The message is the only required field. Missing
+    /// codes are parsed as `Unmapped` and missing or unknown error types as
+    /// `UndefinedError`. Every other field is deserialized as with `serde`, so
+    /// no field is lost and an `InvalidArgumentError` is returned for fields of
+    /// an invalid shape. The returned error is not logged.
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let Value::Object(object) = value else {
+            return Err(invalid_arg_err(
+                "Error value should be a JSON object".to_string(),
+            ));
+        };
+
+        let mut normalized = Map::with_capacity(object.len());
+
+        for (key, value) in object {
+            let (key, value) = match key.as_str() {
+                "message" => ("msg".to_string(), value),
+                "errorType" | "error_type" => (
+                    "error_type".to_string(),
+                    match value.as_str() {
+                        Some(error_type) => serialized_error_type(error_type),
+                        None => value,
+                    },
+                ),
+                "code" | "codes" => ("codes".to_string(), parse_codes(value)),
+                "secondaryCodes" => ("secondary_codes".to_string(), value),
+                "createdAt" => ("created_at".to_string(), value),
+                _ => (key, value),
+            };
+
+            normalized.entry(key).or_insert(value);
+        }
+
+        if !normalized.get("msg").is_some_and(Value::is_string) {
+            return Err(invalid_arg_err(
+                "Error value should contain a `msg` string".to_string(),
+            ));
+        }
+
+        serde_json::from_value(Value::Object(normalized)).map_err(|err| {
+            invalid_arg_err(format!("Error value is not valid: {err}"))
+        })
+    }
+}
+
+impl From<&MappedErrors> for Value {
+    /// Convert a `MappedErrors` into its serialized JSON representation.
+    fn from(error: &MappedErrors) -> Self {
+        serde_json::to_value(error).unwrap_or(Value::Null)
+    }
+}
+
+impl From<MappedErrors> for Value {
+    /// Convert a `MappedErrors` into its serialized JSON representation.
+    fn from(error: MappedErrors) -> Self {
+        Value::from(&error)
+    }
+}
+
+/// Resolve an error type from its slug (`fetching-error`) or its serialized
+/// (`fetchingError`) form into the serialized form. Unknown error types
+/// resolve to `UndefinedError`.
+fn serialized_error_type(value: &str) -> Value {
+    let error_type = ErrorType::from_str(value)
+        .ok()
+        .or_else(|| serde_json::from_value(Value::from(value)).ok())
+        .unwrap_or(ErrorType::UndefinedError);
+
+    serde_json::to_value(error_type).unwrap_or(Value::Null)
+}
+
+/// Convert error codes given as a list of codes or a single string of
+/// delimited codes into their serialized form. Values of any other shape are
+/// kept, so deserialization reports them.
+fn parse_codes(value: Value) -> Value {
+    let codes: Vec<Value> = match &value {
+        Value::Array(items) if items.iter().all(Value::is_string) => {
+            items.to_owned()
+        }
+        Value::String(codes) => codes
+            .split(',')
+            .map(str::trim)
+            .filter(|code| !code.is_empty() && *code != "none")
+            .map(Value::from)
+            .collect(),
+        _ => return value,
+    };
+
+    match codes.is_empty() {
+        true => Value::from("unmapped"),
+        false => json!({ "codes": codes }),
+    }
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::errors::{
+        base::{ErrorCodes, Priority},
+        test_logger::{install_logger, was_logged},
+    };
+    use log::Level;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn test_try_from_full_object() {
+        let error = MappedErrors::try_from(json!({
+            "msg": "user not found",
+            "errorType": "fetching-error",
+            "code": ["ID001", "ID002"],
+            "expected": false,
+            "retryable": true,
+        }))
+        .unwrap();
+
+        assert_eq!(error.msg(), "user not found");
+        assert_eq!(error.error_type(), ErrorType::FetchingError);
+        assert_eq!(
+            error.code(),
+            ErrorCodes::Codes(vec!["ID001".into(), "ID002".into()])
+        );
+        assert!(!error.expected());
+        assert!(error.is_retryable());
+
+        let serialized = serde_json::to_value(&error).unwrap();
+
+        assert_eq!(MappedErrors::try_from(serialized).unwrap(), error);
+    }
+
+    #[test]
+    fn test_try_from_minimal_object() {
+        let error =
+            MappedErrors::try_from(json!({ "msg": "minimal" })).unwrap();
+
+        assert_eq!(error.msg(), "minimal");
+        assert_eq!(error.error_type(), ErrorType::UndefinedError);
+        assert_eq!(error.code(), ErrorCodes::Unmapped);
+
+        assert!(MappedErrors::try_from(json!({ "code": "ID001" })).is_err());
+        assert!(MappedErrors::try_from(json!("not an object")).is_err());
+    }
+
+    #[test]
+    fn test_try_from_unknown_error_type() {
+        let error = MappedErrors::try_from(json!({
+            "msg": "unknown",
+            "errorType": "teapotError",
+            "code": "ID001",
+        }))
+        .unwrap();
+
+        assert_eq!(error.error_type(), ErrorType::UndefinedError);
+        assert!(error.has_str_code("ID001"));
+    }
+
+    #[test]
+    fn test_try_from_keeps_every_field() {
+        let error = MappedErrors::from_parts(
+            "payment failed".to_string(),
+            ErrorType::DependencyError,
+            ErrorCodes::Codes(vec!["ID001".into()]),
+        )
+        .with_secondary_code("ID002")
+        .with_retryable()
+        .with_span(0, 7)
+        .with_attempt(3)
+        .with_priority(Priority::High)
+        .with_timestamp_from(UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+        .with_tag("billing")
+        .with_context_entry("order", "17")
+        .with_cause_str("gateway timeout");
+
+        let value = Value::from(&error);
+        let parsed = MappedErrors::try_from(value.clone()).unwrap();
+
+        assert_eq!(parsed, error);
+        assert_eq!(Value::from(&parsed), value);
+    }
+
+    #[test]
+    fn test_try_from_rejects_invalid_fields() {
+        let _logger = install_logger();
+
+        for invalid in [
+            json!({ "msg": "invalid retryable", "retryable": "yes" }),
+            json!({ "msg": "invalid code", "code": 42 }),
+            json!({ "msg": "invalid span", "span": "0..7" }),
+            json!({ "msg": "invalid priority", "priority": "urgent" }),
+        ] {
+            let err = MappedErrors::try_from(invalid).unwrap_err();
+
+            assert_eq!(err.error_type(), ErrorType::InvalidArgumentError);
+        }
+
+        assert!(!was_logged(Level::Warn, "Error value is not valid"));
+        assert!(!was_logged(Level::Error, "Error value is not valid"));
+    }
+
+    #[test]
+    fn test_into_value() {
+        let error = MappedErrors::from_parts(
+            "not found".to_string(),
+            ErrorType::FetchingError,
+            ErrorCodes::Codes(vec!["ID001".into()]),
+        );
+
+        let value = Value::from(&error);
+
+        assert_eq!(value, serde_json::to_value(&error).unwrap());
+        assert_eq!(value["msg"], "not found");
+        assert_eq!(MappedErrors::try_from(value.clone()).unwrap(), error);
+        assert_eq!(Value::from(error), value);
+    }
+}
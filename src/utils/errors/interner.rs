@@ -0,0 +1,148 @@
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, OnceLock,
+    },
+};
+
+static INTERNED_CODES: OnceLock<Mutex<HashSet<Arc<str>>>> = OnceLock::new();
+
+static CODE_INTERNING: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable the interning of the codes assigned with `with_code`
+/// and of the default codes. Interning is disabled by default.
+///
+/// Codes parsed or deserialized from external input are never interned, so
+/// only the codes known by the application are kept for the process lifetime.
+pub fn set_code_interning(enabled: bool) {
+    CODE_INTERNING.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns the shared representation of the given code if interning is
+/// enabled, and a newly allocated one otherwise.
+pub(super) fn code_from(code: &str) -> Arc<str> {
+    match CODE_INTERNING.load(Ordering::Relaxed) {
+        true => intern_code(code),
+        false => Arc::from(code),
+    }
+}
+
+/// Returns the shared representation of the given error code.
+///
+/// Errors built with the same code share a single backing string, so
+/// repeated codes are allocated only once per process. Codes are never
+/// released, thus only codes from a bounded set should be interned.
+pub fn intern_code(code: &str) -> Arc<str> {
+    let interned = INTERNED_CODES.get_or_init(Default::default);
+
+    let mut interned = match interned.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+
+    if let Some(code) = interned.get(code) {
+        return code.to_owned();
+    }
+
+    let code: Arc<str> = Arc::from(code);
+    interned.insert(code.to_owned());
+    code
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        intern_code, set_code_interning, CODE_INTERNING, INTERNED_CODES,
+    };
+    use crate::utils::errors::{
+        factories::fetching_err, ErrorCodes, MappedErrors,
+    };
+    use std::sync::{atomic::Ordering, Arc, Mutex, MutexGuard};
+
+    static INTERNING_LOCK: Mutex<()> = Mutex::new(());
+
+    /// Serializes the tests changing the interning setting and restores the
+    /// previous one when dropped.
+    struct InterningGuard {
+        previous: bool,
+        _lock: MutexGuard<'static, ()>,
+    }
+
+    impl InterningGuard {
+        fn set(enabled: bool) -> Self {
+            let lock = INTERNING_LOCK
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+            let previous = CODE_INTERNING.load(Ordering::Relaxed);
+            set_code_interning(enabled);
+
+            InterningGuard {
+                previous,
+                _lock: lock,
+            }
+        }
+    }
+
+    impl Drop for InterningGuard {
+        fn drop(&mut self) {
+            set_code_interning(self.previous);
+        }
+    }
+
+    #[test]
+    fn test_intern_code() {
+        let first = intern_code("INTERNED-001");
+        let second = intern_code("INTERNED-001");
+
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(!Arc::ptr_eq(&first, &intern_code("INTERNED-002")));
+    }
+
+    #[test]
+    fn test_errors_share_interned_codes() {
+        let _interning = InterningGuard::set(true);
+
+        let errors = (0..100)
+            .map(|index| {
+                fetching_err(format!("not found {}", index))
+                    .with_code("SHARED-001")
+            })
+            .collect::<Vec<_>>();
+
+        let codes = errors
+            .iter()
+            .map(|error| match error.code() {
+                ErrorCodes::Codes(codes) => codes[0].to_owned(),
+                _ => panic!("expected codes"),
+            })
+            .collect::<Vec<_>>();
+
+        assert!(codes.iter().all(|code| Arc::ptr_eq(code, &codes[0])));
+        assert_eq!(&*codes[0], "SHARED-001");
+    }
+
+    #[test]
+    fn test_parsed_codes_are_not_interned() {
+        let _interning = InterningGuard::set(true);
+
+        let codes = ErrorCodes::parse("PARSED-001").unwrap();
+
+        assert!(MappedErrors::parse_fields(
+            "[codes=PARSED-002 error_type=fetching-error] not found"
+        )
+        .is_some());
+
+        let interned = INTERNED_CODES.get_or_init(Default::default);
+        let interned = interned.lock().unwrap();
+
+        assert_eq!(codes, ErrorCodes::Codes(vec!["PARSED-001".into()]));
+        assert!(!interned.contains("PARSED-001"));
+        assert!(!interned.contains("PARSED-002"));
+    }
+}
@@ -0,0 +1,89 @@
+use super::base::ErrorType;
+use std::sync::RwLock;
+
+static LOG_THRESHOLD: RwLock<Option<ErrorType>> = RwLock::new(None);
+
+/// Set the minimum error type logged on errors construction. Errors with a
+/// severity rank lower than the threshold one (see
+/// `ErrorType::severity_rank`) are not logged. Use `None` to log all errors.
+pub fn set_log_threshold(threshold: Option<ErrorType>) {
+    match LOG_THRESHOLD.write() {
+        Ok(mut guard) => *guard = threshold,
+        Err(poisoned) => *poisoned.into_inner() = threshold,
+    }
+}
+
+/// Check if errors of the given type reach the current log threshold.
+pub(super) fn reaches_log_threshold(error_type: ErrorType) -> bool {
+    let threshold = match LOG_THRESHOLD.read() {
+        Ok(guard) => *guard,
+        Err(poisoned) => *poisoned.into_inner(),
+    };
+
+    match threshold {
+        Some(threshold) => {
+            error_type.severity_rank() >= threshold.severity_rank()
+        }
+        None => true,
+    }
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::errors::{
+        test_logger::{install_logger, was_logged},
+        MappedErrors,
+    };
+    use log::Level;
+
+    /// Restores the default threshold when dropped, even if the test fails.
+    struct ThresholdGuard;
+
+    impl Drop for ThresholdGuard {
+        fn drop(&mut self) {
+            set_log_threshold(None);
+        }
+    }
+
+    #[test]
+    fn test_log_threshold() {
+        let _logger = install_logger();
+        let guard = ThresholdGuard;
+        set_log_threshold(Some(ErrorType::FetchingError));
+
+        MappedErrors::new(
+            "below threshold test error".to_string(),
+            Some(true),
+            None,
+            ErrorType::InvalidArgumentError,
+        );
+
+        MappedErrors::new(
+            "above threshold test error".to_string(),
+            Some(true),
+            None,
+            ErrorType::ExecutionError,
+        );
+
+        drop(guard);
+
+        MappedErrors::new(
+            "below default threshold test error".to_string(),
+            Some(true),
+            None,
+            ErrorType::InvalidArgumentError,
+        );
+
+        assert!(was_logged(
+            Level::Warn,
+            "below default threshold test error"
+        ));
+        assert!(!was_logged(Level::Warn, "below threshold test error"));
+        assert!(was_logged(Level::Warn, "above threshold test error"));
+    }
+}
@@ -10,3 +10,104 @@ pub mod factories;
 /// errors. These factories are used to standardize errors codes.
 mod default_factories;
 pub use default_factories::*;
+
+/// This module contains extensions for `Result` values carrying MappedErrors.
+mod result_ext;
+pub use result_ext::*;
+
+/// This module contains the policies deciding if MappedErrors are
+/// recoverable.
+mod recovery;
+pub use recovery::RecoveryPolicy;
+
+/// This module contains helpers to retry operations returning MappedErrors.
+mod retry;
+pub use retry::*;
+
+/// This module contains the lazy context wrapper used to display MappedErrors.
+mod with_context;
+pub use with_context::*;
+
+/// This module contains the interner sharing error codes across errors.
+mod interner;
+pub use interner::{intern_code, set_code_interning};
+
+/// This module contains the maximum depth of chained MappedErrors.
+mod chain_depth;
+pub use chain_depth::{set_max_chain_depth, DEFAULT_MAX_CHAIN_DEPTH};
+
+/// This module contains the catalog documenting the MappedErrors types.
+mod catalog;
+pub use catalog::CatalogEntry;
+
+/// This module contains the registry of error codes documentation.
+mod code_registry;
+pub use code_registry::CodeRegistry;
+
+/// This module contains the default error codes assigned to each error type.
+mod default_codes;
+pub use default_codes::{
+    default_code_for, set_default_code_resolver, DefaultCodeResolver,
+};
+
+/// This module contains the conversion of `sqlx` errors into MappedErrors.
+#[cfg(feature = "sqlx")]
+mod sqlx_errors;
+
+/// This module contains the conversion of `tokio` timeouts into MappedErrors.
+#[cfg(feature = "tokio")]
+mod tokio_errors;
+
+/// This module contains the `bincode` encoding of MappedErrors.
+#[cfg(feature = "bincode")]
+mod bincode_errors;
+
+/// This module contains the conversion of MappedErrors into `problem_details`.
+#[cfg(feature = "problem-details")]
+mod problem_details_errors;
+#[cfg(feature = "problem-details")]
+pub use problem_details_errors::MappedProblemDetails;
+
+/// This module contains the tolerant conversion of JSON values into
+/// MappedErrors.
+mod from_value;
+
+/// This module contains the serialization of MappedErrors with alternate key
+/// names.
+mod alternate_keys;
+pub use alternate_keys::AlternateKeys;
+
+/// This module contains the serialization of MappedErrors as their flat
+/// `Display` string.
+pub mod display_string;
+
+/// This module contains the serialization of the MappedErrors cause chain.
+mod causes;
+
+/// This module contains the callback invoked on MappedErrors construction.
+mod on_error;
+pub use on_error::{clear_on_error, set_on_error, OnErrorHook};
+
+/// This module contains the collection used to aggregate MappedErrors.
+mod collection;
+pub use collection::*;
+
+/// This module contains the threshold of MappedErrors logging.
+mod log_threshold;
+pub use log_threshold::set_log_threshold;
+
+/// This module contains the rate limiting of MappedErrors logging.
+#[cfg(feature = "rate-limited-logging")]
+mod rate_limit;
+#[cfg(feature = "rate-limited-logging")]
+pub use rate_limit::set_log_rate_limit_interval;
+
+/// This module contains the in-memory buffer of recent MappedErrors.
+#[cfg(feature = "ring-buffer")]
+mod ring_buffer;
+#[cfg(feature = "ring-buffer")]
+pub use ring_buffer::{recent_errors, RING_BUFFER_CAPACITY};
+
+/// This module contains the capturing logger used in tests.
+#[cfg(test)]
+mod test_logger;
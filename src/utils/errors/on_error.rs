@@ -0,0 +1,106 @@
+use super::base::MappedErrors;
+use std::{
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::RwLock,
+};
+
+/// A callback invoked on every `MappedErrors` construction.
+pub type OnErrorHook = fn(&MappedErrors);
+
+static ON_ERROR_HOOK: RwLock<Option<OnErrorHook>> = RwLock::new(None);
+
+/// Register a callback invoked after each error is built. The previously
+/// registered callback, if any, is replaced.
+pub fn set_on_error(hook: OnErrorHook) {
+    match ON_ERROR_HOOK.write() {
+        Ok(mut guard) => *guard = Some(hook),
+        Err(poisoned) => *poisoned.into_inner() = Some(hook),
+    }
+}
+
+/// Unregister the callback registered with `set_on_error`, if any.
+pub fn clear_on_error() {
+    match ON_ERROR_HOOK.write() {
+        Ok(mut guard) => *guard = None,
+        Err(poisoned) => *poisoned.into_inner() = None,
+    }
+}
+
+/// Invoke the registered callback, if any. Panics raised by the callback are
+/// caught and ignored.
+pub(super) fn dispatch_on_error(error: &MappedErrors) {
+    let hook = match ON_ERROR_HOOK.read() {
+        Ok(guard) => *guard,
+        Err(poisoned) => *poisoned.into_inner(),
+    };
+
+    if let Some(hook) = hook {
+        let _ = catch_unwind(AssertUnwindSafe(|| hook(error)));
+    }
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::errors::ErrorType;
+    use std::sync::Mutex;
+
+    static RECORDED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    /// Unregisters the callback when dropped, even if the test fails.
+    struct HookGuard;
+
+    impl Drop for HookGuard {
+        fn drop(&mut self) {
+            clear_on_error();
+        }
+    }
+
+    fn recording_hook(error: &MappedErrors) {
+        if error.msg().contains("panicking hook") {
+            panic!("hook failure");
+        }
+
+        RECORDED.lock().unwrap().push(error.msg());
+    }
+
+    #[test]
+    fn test_on_error_hook() {
+        let guard = HookGuard;
+        set_on_error(recording_hook);
+
+        MappedErrors::new(
+            "hooked error".to_string(),
+            None,
+            None,
+            ErrorType::ExecutionError,
+        );
+
+        let error = MappedErrors::new(
+            "panicking hook error".to_string(),
+            None,
+            None,
+            ErrorType::ExecutionError,
+        );
+
+        drop(guard);
+
+        MappedErrors::new(
+            "unhooked error".to_string(),
+            None,
+            None,
+            ErrorType::ExecutionError,
+        );
+
+        let recorded = RECORDED.lock().unwrap();
+
+        assert!(recorded.iter().any(|msg| msg == "hooked error"));
+        assert!(!recorded.iter().any(|msg| msg == "panicking hook error"));
+        assert!(!recorded.iter().any(|msg| msg == "unhooked error"));
+        assert_eq!(error.msg(), "panicking hook error");
+    }
+}
@@ -0,0 +1,53 @@
+use super::base::MappedErrors;
+use http::StatusCode;
+use problem_details::ProblemDetails;
+use std::collections::HashMap;
+
+/// The problem details built from a `MappedErrors`, with free-form extension
+/// members.
+pub type MappedProblemDetails =
+    ProblemDetails<HashMap<String, serde_json::Value>>;
+
+impl From<MappedErrors> for MappedProblemDetails {
+    /// Convert a `MappedErrors` into a `ProblemDetails`. The title is the
+    /// human readable name of the error type, as `Fetching error`, and the
+    /// error codes are included as the `codes` extension member.
+    fn from(err: MappedErrors) -> Self {
+        let codes = err.code();
+
+        let status = StatusCode::from_u16(err.http_status())
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+        ProblemDetails::new()
+            .with_status(status)
+            .with_title(err.error_type().human_name())
+            .with_detail(err.msg())
+            .with_extensions(HashMap::from([(
+                String::from("codes"),
+                serde_json::json!(codes.as_strs()),
+            )]))
+    }
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::errors::factories::fetching_err;
+
+    #[test]
+    fn test_into_problem_details() {
+        let error =
+            fetching_err("user not found".to_string()).with_code("ID001");
+
+        let details: MappedProblemDetails = error.into();
+
+        assert_eq!(details.status, Some(StatusCode::NOT_FOUND));
+        assert_eq!(details.title.as_deref(), Some("Fetching error"));
+        assert_eq!(details.detail.as_deref(), Some("user not found"));
+        assert_eq!(details.extensions["codes"], serde_json::json!(["ID001"]));
+    }
+}
@@ -0,0 +1,133 @@
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock, RwLock},
+    time::{Duration, Instant},
+};
+
+static INTERVAL: RwLock<Duration> = RwLock::new(Duration::from_secs(1));
+
+static LAST_LOGGED: OnceLock<Mutex<LogRecords>> = OnceLock::new();
+
+/// The instants errors were last logged, by fingerprint.
+#[derive(Default)]
+struct LogRecords {
+    last_logged: HashMap<u64, Instant>,
+    last_pruned: Option<Instant>,
+}
+
+impl LogRecords {
+    /// Check if an error with the given fingerprint should be logged at the
+    /// given instant, recording it if so. Records older than the interval
+    /// are dropped at most once per interval, so errors with distinct
+    /// messages do not accumulate.
+    fn should_log(
+        &mut self,
+        fingerprint: u64,
+        now: Instant,
+        interval: Duration,
+    ) -> bool {
+        if let Some(last) = self.last_logged.get(&fingerprint) {
+            if now.duration_since(*last) < interval {
+                return false;
+            }
+        }
+
+        let prune = match self.last_pruned {
+            Some(last_pruned) => now.duration_since(last_pruned) >= interval,
+            None => true,
+        };
+
+        if prune {
+            self.last_logged
+                .retain(|_, last| now.duration_since(*last) < interval);
+            self.last_pruned = Some(now);
+        }
+
+        self.last_logged.insert(fingerprint, now);
+        true
+    }
+}
+
+/// Set the minimum interval between log records dispatched for errors with
+/// the same fingerprint.
+pub fn set_log_rate_limit_interval(interval: Duration) {
+    match INTERVAL.write() {
+        Ok(mut guard) => *guard = interval,
+        Err(poisoned) => *poisoned.into_inner() = interval,
+    }
+}
+
+/// Check if an error with the given fingerprint should be logged, recording
+/// the current instant if so.
+pub(super) fn should_log(fingerprint: u64) -> bool {
+    let interval = match INTERVAL.read() {
+        Ok(guard) => *guard,
+        Err(poisoned) => *poisoned.into_inner(),
+    };
+
+    let records = LAST_LOGGED.get_or_init(Default::default);
+
+    let mut records = match records.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+
+    records.should_log(fingerprint, Instant::now(), interval)
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::LogRecords;
+    use crate::utils::errors::{
+        factories::fetching_err,
+        test_logger::{count_logged, install_logger},
+        ErrorType, MappedErrors,
+    };
+    use log::Level;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_rate_limited_logging() {
+        let _logger = install_logger();
+
+        for _ in 0..100 {
+            MappedErrors::new(
+                "rate limited test error".to_string(),
+                Some(true),
+                None,
+                ErrorType::FetchingError,
+            );
+        }
+
+        assert_eq!(count_logged(Level::Warn, "rate limited test error"), 1);
+
+        let first = fetching_err("first".to_string());
+        let second = fetching_err("second".to_string());
+
+        assert_ne!(first.fingerprint(), second.fingerprint());
+    }
+
+    #[test]
+    fn test_expired_records_are_dropped() {
+        let mut records = LogRecords::default();
+        let interval = Duration::from_secs(1);
+        let start = Instant::now();
+
+        for fingerprint in 0..100 {
+            assert!(records.should_log(fingerprint, start, interval));
+        }
+
+        assert!(!records.should_log(0, start, interval));
+        assert_eq!(records.last_logged.len(), 100);
+
+        let later = start + interval * 2;
+
+        assert!(records.should_log(100, later, interval));
+        assert_eq!(records.last_logged.len(), 1);
+        assert!(records.should_log(0, later, interval));
+    }
+}
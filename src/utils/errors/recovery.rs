@@ -0,0 +1,51 @@
+use super::base::MappedErrors;
+
+/// A policy deciding if an error is recoverable at a given layer, as a use
+/// case falling back to defaults when a record is not found. Applications
+/// implement it to inject their own recovery rules.
+pub trait RecoveryPolicy {
+    /// This method returns a boolean indicating if the error is recoverable.
+    fn is_recoverable(&self, error: &MappedErrors) -> bool;
+}
+
+impl MappedErrors {
+    /// This method returns a boolean indicating if the current error is
+    /// recoverable according to the given policy.
+    pub fn is_recoverable_at(&self, policy: &dyn RecoveryPolicy) -> bool {
+        policy.is_recoverable(self)
+    }
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::errors::{
+        factories::{execution_err, fetching_err, invalid_arg_err},
+        ErrorType,
+    };
+
+    struct FallbackOnNotFound;
+
+    impl RecoveryPolicy for FallbackOnNotFound {
+        fn is_recoverable(&self, error: &MappedErrors) -> bool {
+            error.error_type() == ErrorType::FetchingError
+        }
+    }
+
+    #[test]
+    fn test_is_recoverable_at() {
+        let policy = FallbackOnNotFound;
+
+        assert!(
+            fetching_err("not found".to_string()).is_recoverable_at(&policy)
+        );
+        assert!(!execution_err("failed".to_string()).is_recoverable_at(&policy));
+        assert!(
+            !invalid_arg_err("invalid".to_string()).is_recoverable_at(&policy)
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use super::{base::MappedErrors, factories::execution_err};
+use log::Level;
+use std::{
+    process::{ExitCode, Termination},
+    sync::LockResult,
+};
+
+/// Extension methods for `Result` values carrying a `MappedErrors`.
+pub trait MappedErrorsResultExt<T> {
+    /// Log the inner error at the given level, if any, and return the result
+    /// unchanged.
+    fn log_err(self, level: Level) -> Self;
+
+    /// Convert the result into the exit code of a process. Errors are printed
+    /// to the standard error and mapped to the exit code of their type, as
+    /// in `fn main() -> ExitCode { run().into_exit_code() }`.
+    fn into_exit_code(self) -> ExitCode;
+}
+
+impl<T> MappedErrorsResultExt<T> for Result<T, MappedErrors> {
+    fn log_err(self, level: Level) -> Self {
+        self.map_err(|err| err.or_log(level))
+    }
+
+    fn into_exit_code(self) -> ExitCode {
+        match self {
+            Ok(_) => ExitCode::SUCCESS,
+            Err(err) => err.report(),
+        }
+    }
+}
+
+/// Extension methods for `LockResult` values returned by `Mutex` and `RwLock`.
+pub trait LockResultExt<T> {
+    /// Convert a poisoned lock into an `ExecutionError` with the
+    /// `lock-poisoned` code.
+    #[allow(clippy::result_large_err)]
+    fn map_lock_err(self) -> Result<T, MappedErrors>;
+}
+
+impl<T> LockResultExt<T> for LockResult<T> {
+    fn map_lock_err(self) -> Result<T, MappedErrors> {
+        self.map_err(|err| {
+            execution_err(format!("Lock poisoned: {}", err))
+                .with_code("lock-poisoned")
+        })
+    }
+}
+
+/// Test helpers for `Result` values carrying a `MappedErrors`.
+#[cfg(any(test, feature = "testing"))]
+pub trait MappedErrorsTestExt<T> {
+    /// Return the inner value or panic with the `Display` representation of
+    /// the error, instead of the `Debug` one used by `unwrap`.
+    fn expect_ok(self) -> T;
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl<T> MappedErrorsTestExt<T> for Result<T, MappedErrors> {
+    #[track_caller]
+    fn expect_ok(self) -> T {
+        match self {
+            Ok(value) => value,
+            Err(err) => panic!("called `expect_ok` on an error: {}", err),
+        }
+    }
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+#[allow(clippy::result_large_err)]
+mod tests {
+    use super::*;
+    use crate::utils::errors::{
+        test_logger::{count_logged, install_logger, was_logged},
+        ErrorType,
+    };
+    use std::{
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    #[test]
+    fn test_or_log() {
+        let _logger = install_logger();
+
+        let error = execution_err("or_log test error".to_string())
+            .with_code("ID001")
+            .or_log(Level::Info);
+
+        assert!(was_logged(Level::Info, "or_log test error"));
+        assert_eq!(error.msg(), "or_log test error");
+        assert!(error.has_str_code("ID001"));
+    }
+
+    #[test]
+    fn test_or_log_skips_logged_errors() {
+        let _logger = install_logger();
+
+        let error = execution_err("logged once test error".to_string());
+
+        assert!(!error.is_logged());
+
+        let error = error.or_log(Level::Info);
+
+        assert!(error.is_logged());
+
+        let _ = Err::<(), _>(error).log_err(Level::Info);
+
+        assert_eq!(count_logged(Level::Info, "logged once test error"), 1);
+
+        let error = execution_err("marked test error".to_string())
+            .mark_logged()
+            .or_log(Level::Info);
+
+        assert!(!was_logged(Level::Info, "marked test error"));
+        assert!(error.is_logged());
+    }
+
+    #[test]
+    fn test_log_err() {
+        let _logger = install_logger();
+
+        fn failing() -> Result<(), MappedErrors> {
+            execution_err("log_err test error".to_string()).as_error()
+        }
+
+        fn propagating() -> Result<(), MappedErrors> {
+            failing().log_err(Level::Debug)?;
+            Ok(())
+        }
+
+        let error = propagating().unwrap_err();
+
+        assert!(was_logged(Level::Debug, "log_err test error"));
+        assert_eq!(error.msg(), "log_err test error");
+    }
+
+    #[test]
+    fn test_into_exit_code() {
+        let result: Result<(), MappedErrors> = Ok(());
+
+        assert_eq!(result.into_exit_code(), ExitCode::SUCCESS);
+
+        let result: Result<(), MappedErrors> =
+            Err(execution_err("exit code test error".to_string())
+                .with_error_type(ErrorType::ConfigurationError));
+
+        assert_eq!(result.into_exit_code(), ExitCode::from(78));
+    }
+
+    #[test]
+    fn test_map_lock_err() {
+        let mutex = Arc::new(Mutex::new(0));
+        let cloned = Arc::clone(&mutex);
+
+        let _ = thread::spawn(move || {
+            let _guard = cloned.lock().unwrap();
+            panic!("poisoning the mutex");
+        })
+        .join();
+
+        let error = mutex.lock().map_lock_err().unwrap_err();
+
+        assert_eq!(error.error_type(), ErrorType::ExecutionError);
+        assert!(error.has_str_code("lock-poisoned"));
+    }
+
+    #[test]
+    fn test_expect_ok() {
+        let result: Result<i32, MappedErrors> = Ok(1);
+
+        assert_eq!(result.expect_ok(), 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "called `expect_ok` on an error: [codes=ID001 error_type=execution-error] boom"
+    )]
+    fn test_expect_ok_panics_with_display() {
+        let result: Result<(), MappedErrors> =
+            Err(execution_err("boom".to_string()).with_code("ID001"));
+
+        result.expect_ok();
+    }
+}
@@ -0,0 +1,98 @@
+use super::base::MappedErrors;
+use std::{thread::sleep, time::Duration};
+
+/// Execute `f` until it succeeds, up to `max_attempts` times.
+///
+/// A new attempt is made only while the returned error is retryable (see
+/// `MappedErrors::is_retryable`) and attempts remain. The `backoff` duration is
+/// waited between attempts. The last error is returned otherwise, with the
+/// attempt that dispatched it.
+#[allow(clippy::result_large_err)]
+pub fn retry_with<T, F>(
+    max_attempts: usize,
+    backoff: Duration,
+    mut f: F,
+) -> Result<T, MappedErrors>
+where
+    F: FnMut() -> Result<T, MappedErrors>,
+{
+    let mut attempt = 1;
+
+    loop {
+        match f() {
+            Ok(value) => return Ok(value),
+            Err(err) if err.is_retryable() && attempt < max_attempts => {
+                attempt += 1;
+                sleep(backoff);
+            }
+            Err(err) => {
+                let attempt = u32::try_from(attempt).unwrap_or(u32::MAX);
+                return Err(err.with_attempt(attempt));
+            }
+        }
+    }
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+#[allow(clippy::result_large_err)]
+mod tests {
+    use super::*;
+    use crate::utils::errors::factories::{execution_err, fetching_err};
+
+    #[test]
+    fn test_retry_with_succeeds_after_failures() {
+        let mut calls = 0;
+
+        let result = retry_with(5, Duration::ZERO, || {
+            calls += 1;
+
+            if calls < 3 {
+                return execution_err("transient".to_string())
+                    .with_retryable()
+                    .as_error();
+            }
+
+            Ok(calls)
+        });
+
+        assert_eq!(result.unwrap(), 3);
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn test_retry_with_non_retryable_error() {
+        let mut calls = 0;
+
+        let result: Result<(), MappedErrors> =
+            retry_with(5, Duration::ZERO, || {
+                calls += 1;
+                fetching_err("permanent".to_string()).as_error()
+            });
+
+        assert_eq!(result.unwrap_err().msg(), "permanent");
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_retry_with_exhausted_attempts() {
+        let mut calls = 0;
+
+        let result: Result<(), MappedErrors> =
+            retry_with(3, Duration::ZERO, || {
+                calls += 1;
+                execution_err(format!("attempt {}", calls))
+                    .with_retryable()
+                    .as_error()
+            });
+
+        let error = result.unwrap_err();
+
+        assert_eq!(error.msg(), "attempt 3");
+        assert_eq!(error.attempt(), Some(3));
+        assert_eq!(calls, 3);
+    }
+}
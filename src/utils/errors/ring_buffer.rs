@@ -0,0 +1,98 @@
+use super::base::MappedErrors;
+use std::{collections::VecDeque, sync::Mutex};
+
+/// The maximum number of errors kept in the global buffer.
+pub const RING_BUFFER_CAPACITY: usize = 100;
+
+static RECENT_ERRORS: Mutex<RingBuffer> =
+    Mutex::new(RingBuffer::new(RING_BUFFER_CAPACITY));
+
+/// A bounded buffer keeping the most recent errors. The oldest error is
+/// dropped when a new one is included in a full buffer.
+struct RingBuffer {
+    capacity: usize,
+    errors: VecDeque<MappedErrors>,
+}
+
+impl RingBuffer {
+    const fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            errors: VecDeque::new(),
+        }
+    }
+
+    fn push(&mut self, error: MappedErrors) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        while self.errors.len() >= self.capacity {
+            self.errors.pop_front();
+        }
+
+        self.errors.push_back(error);
+    }
+
+    fn to_vec(&self) -> Vec<MappedErrors> {
+        self.errors.iter().cloned().collect()
+    }
+}
+
+/// This method returns the most recently built errors, from the oldest to the
+/// newest. At most `RING_BUFFER_CAPACITY` errors are kept.
+pub fn recent_errors() -> Vec<MappedErrors> {
+    match RECENT_ERRORS.lock() {
+        Ok(guard) => guard.to_vec(),
+        Err(poisoned) => poisoned.into_inner().to_vec(),
+    }
+}
+
+/// Include the error in the global buffer of recent errors.
+pub(super) fn record(error: &MappedErrors) {
+    match RECENT_ERRORS.lock() {
+        Ok(mut guard) => guard.push(error.to_owned()),
+        Err(poisoned) => poisoned.into_inner().push(error.to_owned()),
+    }
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::errors::ErrorType;
+
+    #[test]
+    fn test_ring_buffer_keeps_most_recent_errors() {
+        let mut buffer = RingBuffer::new(3);
+
+        for index in 0..5 {
+            buffer.push(MappedErrors::default(format!("error {}", index)));
+        }
+
+        let messages: Vec<String> =
+            buffer.to_vec().iter().map(MappedErrors::msg).collect();
+
+        assert_eq!(messages, vec!["error 2", "error 3", "error 4"]);
+    }
+
+    #[test]
+    fn test_recent_errors() {
+        for index in 0..RING_BUFFER_CAPACITY + 10 {
+            MappedErrors::new(
+                format!("recent error {}", index),
+                None,
+                None,
+                ErrorType::ExecutionError,
+            );
+        }
+
+        let recent = recent_errors();
+
+        assert_eq!(recent.len(), RING_BUFFER_CAPACITY);
+        assert!(!recent.iter().any(|e| e.msg() == "recent error 0"));
+    }
+}
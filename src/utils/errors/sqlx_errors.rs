@@ -0,0 +1,116 @@
+use super::base::{ErrorType, MappedErrors};
+
+impl From<sqlx::Error> for MappedErrors {
+    /// Convert a `sqlx::Error` into a `MappedErrors`. Missing rows are mapped
+    /// to fetching errors with the `not-found` code, unique violations to
+    /// creation errors with the `conflict` code, and any other error to an
+    /// invalid repository error. The database message is preserved.
+    fn from(err: sqlx::Error) -> Self {
+        let (error_type, code) = match &err {
+            sqlx::Error::RowNotFound => {
+                (ErrorType::FetchingError, Some("not-found"))
+            }
+            sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
+                (ErrorType::CreationError, Some("conflict"))
+            }
+            _ => (ErrorType::InvalidRepositoryError, None),
+        };
+
+        let msg = match &err {
+            sqlx::Error::Database(db_err) => db_err.message().to_string(),
+            _ => err.to_string(),
+        };
+
+        let error = MappedErrors::default(msg).with_error_type(error_type);
+
+        match code {
+            Some(code) => error.with_code(code),
+            None => error,
+        }
+    }
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::error::{DatabaseError, ErrorKind};
+    use std::{
+        error::Error,
+        fmt::{Display, Formatter, Result as FmtResult},
+    };
+
+    #[derive(Debug)]
+    struct MockDatabaseError {
+        msg: &'static str,
+        unique: bool,
+    }
+
+    impl Display for MockDatabaseError {
+        fn fmt(&self, f: &mut Formatter) -> FmtResult {
+            write!(f, "{}", self.msg)
+        }
+    }
+
+    impl Error for MockDatabaseError {}
+
+    impl DatabaseError for MockDatabaseError {
+        fn message(&self) -> &str {
+            self.msg
+        }
+
+        fn as_error(&self) -> &(dyn Error + Send + Sync + 'static) {
+            self
+        }
+
+        fn as_error_mut(&mut self) -> &mut (dyn Error + Send + Sync + 'static) {
+            self
+        }
+
+        fn into_error(
+            self: Box<Self>,
+        ) -> Box<dyn Error + Send + Sync + 'static> {
+            self
+        }
+
+        fn kind(&self) -> ErrorKind {
+            match self.unique {
+                true => ErrorKind::UniqueViolation,
+                false => ErrorKind::Other,
+            }
+        }
+    }
+
+    fn database_error(msg: &'static str, unique: bool) -> sqlx::Error {
+        sqlx::Error::Database(Box::new(MockDatabaseError { msg, unique }))
+    }
+
+    #[test]
+    fn test_row_not_found() {
+        let error: MappedErrors = sqlx::Error::RowNotFound.into();
+
+        assert_eq!(error.error_type(), ErrorType::FetchingError);
+        assert!(error.has_str_code("not-found"));
+    }
+
+    #[test]
+    fn test_unique_violation() {
+        let error: MappedErrors = database_error("duplicate key", true).into();
+
+        assert_eq!(error.error_type(), ErrorType::CreationError);
+        assert!(error.has_str_code("conflict"));
+        assert_eq!(error.msg(), "duplicate key");
+    }
+
+    #[test]
+    fn test_other_database_error() {
+        let error: MappedErrors =
+            database_error("connection reset", false).into();
+
+        assert_eq!(error.error_type(), ErrorType::InvalidRepositoryError);
+        assert_eq!(error.msg(), "connection reset");
+    }
+}
@@ -0,0 +1,58 @@
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::sync::{Mutex, MutexGuard};
+
+/// A logger capturing the dispatched records, used to assert logging side
+/// effects in tests.
+struct CapturingLogger;
+
+static RECORDS: Mutex<Vec<(Level, String)>> = Mutex::new(Vec::new());
+
+static LOGGER: CapturingLogger = CapturingLogger;
+
+static LOGGING_LOCK: Mutex<()> = Mutex::new(());
+
+impl Log for CapturingLogger {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        RECORDS
+            .lock()
+            .unwrap()
+            .push((record.level(), record.args().to_string()));
+    }
+
+    fn flush(&self) {}
+}
+
+/// Install the capturing logger. It is safe to call it many times.
+///
+/// The returned guard serializes the tests asserting logging side effects,
+/// so settings changing what is logged (e.g. `set_log_threshold`) do not leak
+/// into each other. Keep it alive for the whole test.
+pub(crate) fn install_logger() -> MutexGuard<'static, ()> {
+    let guard = LOGGING_LOCK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(LevelFilter::Trace);
+    guard
+}
+
+/// Count the captured records with the given level containing the message.
+pub(crate) fn count_logged(level: Level, msg: &str) -> usize {
+    RECORDS
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|(l, m)| *l == level && m.contains(msg))
+        .count()
+}
+
+/// Check if a record with the given level containing the message was
+/// captured.
+pub(crate) fn was_logged(level: Level, msg: &str) -> bool {
+    count_logged(level, msg) > 0
+}
@@ -0,0 +1,37 @@
+use super::base::{ErrorType, MappedErrors};
+use tokio::time::error::Elapsed;
+
+impl From<Elapsed> for MappedErrors {
+    /// Convert an elapsed `tokio::time::timeout` into a dependency error with
+    /// the `timeout` code. Such errors are retryable by default.
+    fn from(err: Elapsed) -> Self {
+        MappedErrors::default(format!("Operation timed out: {}", err))
+            .with_error_type(ErrorType::DependencyError)
+            .with_code("timeout")
+    }
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{future::pending, time::Duration};
+    use tokio::time::timeout;
+
+    async fn slow_operation() -> Result<(), MappedErrors> {
+        timeout(Duration::from_millis(1), pending::<()>()).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_from_elapsed() {
+        let error = slow_operation().await.unwrap_err();
+
+        assert_eq!(error.error_type(), ErrorType::DependencyError);
+        assert!(error.has_str_code("timeout"));
+        assert!(error.is_retryable());
+    }
+}
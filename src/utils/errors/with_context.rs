@@ -0,0 +1,58 @@
+use super::base::MappedErrors;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// A borrowed view of a `MappedErrors` prefixed by a context message.
+///
+/// The context is only rendered when the wrapper is displayed, so no
+/// allocation is done and the underlying error is kept unchanged.
+#[derive(Debug, Clone, Copy)]
+pub struct WithContext<'a> {
+    context: &'a str,
+    error: &'a MappedErrors,
+}
+
+impl<'a> WithContext<'a> {
+    pub(super) fn new(context: &'a str, error: &'a MappedErrors) -> Self {
+        Self { context, error }
+    }
+
+    /// This method returns the context message of the wrapper.
+    pub fn context(&self) -> &'a str {
+        self.context
+    }
+
+    /// This method returns the wrapped error.
+    pub fn error(&self) -> &'a MappedErrors {
+        self.error
+    }
+}
+
+impl Display for WithContext<'_> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}: {}", self.context, self.error)
+    }
+}
+
+// * ---------------------------------------------------------------------------
+// * TESTS
+// * ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::errors::factories::fetching_err;
+
+    #[test]
+    fn test_with_context_display() {
+        let error = fetching_err("user not found".to_string());
+        let original = error.to_owned();
+
+        let with_context = error.context("while loading user");
+
+        assert_eq!(
+            with_context.to_string(),
+            format!("while loading user: {}", error)
+        );
+        assert_eq!(with_context.error(), &original);
+        assert_eq!(error, original);
+    }
+}